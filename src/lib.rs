@@ -1,3 +1,4 @@
+use std::fmt;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -25,7 +26,38 @@ use std::process::Command;
 pub struct MO2Command {
     mo2_path: PathBuf,
     program_path: PathBuf,
-    arguments: Vec<String>,
+    arguments: Vec<Argument>,
+    quoting: QuotingStyle,
+}
+
+/// A single entry in the `-a` argument list.
+///
+/// Mirrors the distinction `std::process::Command` draws between `arg` and
+/// the unstable `CommandExt::raw_arg`: a [`Regular`](Argument::Regular)
+/// argument is escaped according to the command's [`QuotingStyle`] before
+/// being emitted, while a [`Raw`](Argument::Raw) argument is spliced into the
+/// payload verbatim.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Argument {
+    Regular(String),
+    Raw(String),
+}
+
+/// How `Regular` arguments are escaped for the `-a` payload.
+///
+/// The right escaping depends on how the command tail is ultimately
+/// consumed: a program launched directly via `CreateProcess` expects
+/// `CommandLineToArgvW` rules, but a batch wrapper or PowerShell one-liner
+/// run through `cmd.exe` has entirely different metacharacter rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuotingStyle {
+    /// `CommandLineToArgvW`-style backslash/quote escaping. The default.
+    #[default]
+    Msvcrt,
+    /// `cmd.exe`-style escaping: `" & | < > ^ ( )` are caret-escaped.
+    CmdExe,
+    /// No escaping at all; the argument is emitted verbatim.
+    None,
 }
 
 impl MO2Command {
@@ -40,12 +72,34 @@ impl MO2Command {
             mo2_path: mo2_path.as_ref().to_path_buf(),
             program_path: program_path.as_ref().to_path_buf(),
             arguments: Vec::new(),
+            quoting: QuotingStyle::default(),
         }
     }
 
+    /// Sets the escaping rules applied to `Regular` arguments in both
+    /// [`Self::build`]'s textual output and the `-a` value [`Self::execute`]
+    /// passes to `Command::arg`.
+    ///
+    /// Defaults to [`QuotingStyle::Msvcrt`]. `Raw` arguments added via
+    /// [`Self::raw_arg`] are unaffected by this setting, since they already
+    /// bypass escaping entirely. The `CommandLineToArgvW`-compatible encoding
+    /// `Command::arg` itself applies when the process is actually spawned is
+    /// a separate, transparent layer that delivers the whole `-a` string as
+    /// one argv entry — it does not re-split the individual arguments MO2
+    /// recovers from that string, so this encoding is still required.
+    pub fn quoting(mut self, style: QuotingStyle) -> Self {
+        self.quoting = style;
+        self
+    }
+
     /// Adds a single argument to be passed to the program.
+    ///
+    /// The argument is escaped according to the style set by
+    /// [`Self::quoting`] (`CommandLineToArgvW` rules by default) before being
+    /// placed in the `-a` payload. Use [`Self::raw_arg`] if the argument must
+    /// be spliced in untouched.
     pub fn arg(mut self, arg: impl Into<String>) -> Self {
-        self.arguments.push(arg.into());
+        self.arguments.push(Argument::Regular(arg.into()));
         self
     }
 
@@ -55,7 +109,63 @@ impl MO2Command {
         I: IntoIterator<Item = S>,
         S: Into<String>,
     {
-        self.arguments.extend(args.into_iter().map(|s| s.into()));
+        self.arguments
+            .extend(args.into_iter().map(|s| Argument::Regular(s.into())));
+        self
+    }
+
+    /// Parses a command string previously produced by [`Self::build`] back
+    /// into an `MO2Command`.
+    ///
+    /// The inner tokenizer implements the `CommandLineToArgvW` state
+    /// machine: the first three tokens are expected to be the MO2 path, the
+    /// literal `run` keyword, and the program path, and if a `-a` token
+    /// follows, its value is re-tokenized into the individual arguments.
+    /// Quoting is grouping syntax rather than literal data, so a quoted
+    /// argument comes back as the unquoted value the downstream program
+    /// would actually receive, the same way `CommandLineToArgvW` itself
+    /// strips matching quotes. This lets a command string loaded from a
+    /// saved MO2 instance config be modified and re-serialized.
+    pub fn parse(command: &str) -> Result<Self, ParseError> {
+        let mut tokens = tokenize_command_line(command).into_iter();
+
+        let mo2_path = tokens.next().ok_or(ParseError::MissingMo2Path)?;
+        let run_keyword = tokens.next().ok_or(ParseError::MissingRunKeyword)?;
+        if run_keyword != "run" {
+            return Err(ParseError::MissingRunKeyword);
+        }
+        let program_path = tokens.next().ok_or(ParseError::MissingProgramPath)?;
+
+        let mut arguments = Vec::new();
+        if let Some(flag) = tokens.next() {
+            if flag != "-a" {
+                return Err(ParseError::UnexpectedToken(flag));
+            }
+            let args_value = tokens.next().ok_or(ParseError::MissingArgumentsValue)?;
+            arguments = tokenize_command_line(&args_value)
+                .into_iter()
+                .map(Argument::Regular)
+                .collect();
+        }
+
+        Ok(Self {
+            mo2_path: PathBuf::from(mo2_path),
+            program_path: PathBuf::from(program_path),
+            arguments,
+            quoting: QuotingStyle::default(),
+        })
+    }
+
+    /// Adds a raw, unescaped fragment to the `-a` payload.
+    ///
+    /// Unlike [`Self::arg`], no `CommandLineToArgvW` quoting or escaping is
+    /// applied to `text` — it is spliced into the argument list exactly as
+    /// given. This is needed when the downstream program expects a command
+    /// tail that does not follow `CommandLineToArgvW` rules, such as a
+    /// forwarded `cmd.exe /c` string or a pre-quoted script block, where the
+    /// builder's automatic escaping would otherwise corrupt the value.
+    pub fn raw_arg(mut self, text: impl Into<String>) -> Self {
+        self.arguments.push(Argument::Raw(text.into()));
         self
     }
 
@@ -64,7 +174,12 @@ impl MO2Command {
     /// The command will be in the format:
     /// `"<mo2_path>" run "<program_path>" -a "<arguments>"`
     ///
-    /// Arguments are properly escaped for Windows command line.
+    /// Each `Regular` argument is escaped per [`Self::quoting`], then the
+    /// joined payload is itself encoded as a single `CommandLineToArgvW`
+    /// token regardless of `Self::quoting` — this is what actually delivers
+    /// it to MO2 as one `-a` value, so any quotes the per-argument escaping
+    /// introduced must survive being decoded again by MO2's own invocation
+    /// line.
     pub fn build(&self) -> String {
         let mo2_path = quote_path(&self.mo2_path);
         let program_path = quote_path(&self.program_path);
@@ -72,13 +187,32 @@ impl MO2Command {
         if self.arguments.is_empty() {
             format!(r#"{} run {}"#, mo2_path, program_path)
         } else {
-            let args_string = self.arguments.join(" ");
-            // Escape quotes in the arguments string for the -a parameter
-            let escaped_args = escape_for_mo2_args(&args_string);
-            format!(r#"{} run {} -a "{}""#, mo2_path, program_path, escaped_args)
+            let args_string = self.encode_arguments();
+            let args_payload = escape_arg_for_mo2(&args_string, true);
+            format!(r#"{} run {} -a {}"#, mo2_path, program_path, args_payload)
         }
     }
 
+    /// Encodes every argument and joins the results with spaces, producing
+    /// the per-argument payload used by [`Self::build`].
+    ///
+    /// `Regular` arguments are escaped according to [`Self::quoting`]; `Raw`
+    /// arguments are always spliced in untouched.
+    fn encode_arguments(&self) -> String {
+        self.arguments
+            .iter()
+            .map(|arg| match arg {
+                Argument::Regular(text) => match self.quoting {
+                    QuotingStyle::Msvcrt => escape_arg_for_mo2(text, false),
+                    QuotingStyle::CmdExe => escape_arg_for_cmd_exe(text),
+                    QuotingStyle::None => text.clone(),
+                },
+                Argument::Raw(text) => text.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// Creates a `std::process::Command` ready to execute.
     ///
     /// This is the recommended way to run the MO2 command as it properly
@@ -108,9 +242,7 @@ impl MO2Command {
 
         if !self.arguments.is_empty() {
             cmd.arg("-a");
-            // Join and escape the arguments for the -a parameter
-            let args_string = self.arguments.join(" ");
-            cmd.arg(args_string);
+            cmd.arg(self.encode_arguments());
         }
 
         cmd
@@ -122,17 +254,185 @@ fn quote_path(path: &Path) -> String {
     format!(r#""{}""#, path.display())
 }
 
-/// Escapes a string for use within MO2's -a argument.
+/// Escapes a single argument for inclusion in MO2's `-a` payload.
+///
+/// This follows the `CommandLineToArgvW` encoding rules: if the argument is
+/// non-empty and contains none of space, tab, or `"`, it is emitted
+/// verbatim. Otherwise it is wrapped in `"`: walking the characters and
+/// tracking a run-length of consecutive backslashes, a `"` emits twice the
+/// backslash run plus `\"`; end-of-string emits twice the backslash run
+/// before the closing `"`; anything else emits the backslashes as-is
+/// followed by the character. Without this, a value like `C:\path\`
+/// directly followed by a quote would have its trailing backslash consumed
+/// together with the quote by the Windows command line parser, and a value
+/// containing an unquoted space would be split into multiple arguments.
+///
+/// `force_quotes` always takes the wrapped path regardless of content. This
+/// is used to encode an already-escaped blob — such as the full `-a`
+/// payload in [`MO2Command::build`] — as a single token, so any quotes and
+/// backslashes it already contains survive being decoded again by MO2's own
+/// invocation line.
+fn escape_arg_for_mo2(arg: &str, force_quotes: bool) -> String {
+    let needs_quotes =
+        force_quotes || arg.is_empty() || arg.contains([' ', '\t', '"']);
+    if !needs_quotes {
+        return arg.to_string();
+    }
+
+    wrap_in_quotes(arg)
+}
+
+/// Wraps `arg` in `"` using the `CommandLineToArgvW` quoting rules: walking
+/// the characters and tracking a run-length of consecutive backslashes, a
+/// `"` emits twice the backslash run plus `\"`; end-of-string emits twice the
+/// backslash run before the closing `"`; anything else emits the backslashes
+/// as-is followed by the character. This is what lets the wrapped text
+/// survive being decoded again by a `CommandLineToArgvW`-style tokenizer,
+/// such as MO2's own parsing of its `-a` value.
+fn wrap_in_quotes(arg: &str) -> String {
+    let mut escaped = String::with_capacity(arg.len() + 2);
+    escaped.push('"');
+    let mut backslashes = 0usize;
+
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                escaped.extend(std::iter::repeat_n('\\', backslashes * 2 + 1));
+                escaped.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                escaped.extend(std::iter::repeat_n('\\', backslashes));
+                escaped.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+
+    escaped.extend(std::iter::repeat_n('\\', backslashes * 2));
+    escaped.push('"');
+    escaped
+}
+
+/// Escapes a single argument using `cmd.exe`'s metacharacter rules.
+///
+/// Unlike `CommandLineToArgvW`, `cmd.exe` does not use backslash escaping;
+/// instead, each of its metacharacters (`" & | < > ^ ( )`) must be preceded
+/// by a caret so it is treated as a literal character rather than being
+/// interpreted by the shell. The caret-escaped text is then additionally
+/// wrapped in quotes using the `CommandLineToArgvW` rules whenever it
+/// contains a space or tab: MO2's own `-a` tokenizer parses the whole
+/// payload with that algorithm regardless of `QuotingStyle`, so an unquoted
+/// space would still split the argument in two before cmd.exe ever sees it.
+fn escape_arg_for_cmd_exe(arg: &str) -> String {
+    let mut escaped = String::with_capacity(arg.len());
+    for c in arg.chars() {
+        if matches!(c, '"' | '&' | '|' | '<' | '>' | '^' | '(' | ')') {
+            escaped.push('^');
+        }
+        escaped.push(c);
+    }
+
+    if escaped.contains([' ', '\t']) {
+        wrap_in_quotes(&escaped)
+    } else {
+        escaped
+    }
+}
+
+/// Tokenizes a command line using the `CommandLineToArgvW` state machine.
 ///
-/// This handles the nested quoting scenario where the -a argument itself
-/// is quoted, and may contain quotes within it.
-fn escape_for_mo2_args(args: &str) -> String {
-    // In Windows cmd.exe, within a quoted string, quotes need to be escaped with backslash
-    // However, MO2 may have its own parsing rules. Based on the Python example,
-    // it appears that quotes within the -a string need backslash escaping.
-    args.replace('"', r#"\""#)
+/// This is the exact inverse of [`escape_arg_for_mo2`]: unquoted spaces and
+/// tabs separate tokens, a run of `N` backslashes followed by a `"` emits `N/2`
+/// backslashes and toggles quote-mode if `N` is even, or emits a literal `"`
+/// without toggling if `N` is odd, and backslashes not followed by a `"` are
+/// passed through literally.
+fn tokenize_command_line(command: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = command.chars().peekable();
+
+    loop {
+        while matches!(chars.peek(), Some(' ' | '\t')) {
+            chars.next();
+        }
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut token = String::new();
+        let mut in_quotes = false;
+        loop {
+            match chars.peek() {
+                None => break,
+                Some(' ' | '\t') if !in_quotes => break,
+                Some('\\') => {
+                    let mut backslashes = 0usize;
+                    while chars.peek() == Some(&'\\') {
+                        backslashes += 1;
+                        chars.next();
+                    }
+                    if chars.peek() == Some(&'"') {
+                        chars.next();
+                        token.extend(std::iter::repeat_n('\\', backslashes / 2));
+                        if backslashes % 2 == 1 {
+                            token.push('"');
+                        } else {
+                            in_quotes = !in_quotes;
+                        }
+                    } else {
+                        token.extend(std::iter::repeat_n('\\', backslashes));
+                    }
+                }
+                Some('"') => {
+                    chars.next();
+                    in_quotes = !in_quotes;
+                }
+                Some(&c) => {
+                    token.push(c);
+                    chars.next();
+                }
+            }
+        }
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+/// Errors returned by [`MO2Command::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The command string was empty, so no MO2 path could be read.
+    MissingMo2Path,
+    /// The second token was missing or was not the literal `run` keyword.
+    MissingRunKeyword,
+    /// No program path token followed the `run` keyword.
+    MissingProgramPath,
+    /// A token appeared where `-a` was expected.
+    UnexpectedToken(String),
+    /// A `-a` token was present but had no following value.
+    MissingArgumentsValue,
 }
 
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingMo2Path => write!(f, "command string is missing the MO2 path"),
+            Self::MissingRunKeyword => write!(f, "command string is missing the `run` keyword"),
+            Self::MissingProgramPath => write!(f, "command string is missing the program path"),
+            Self::UnexpectedToken(token) => {
+                write!(f, "expected `-a`, found `{}`", token)
+            }
+            Self::MissingArgumentsValue => {
+                write!(f, "command string has a `-a` flag with no value")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,6 +468,10 @@ mod tests {
 
     #[test]
     fn test_command_with_quoted_plugin_name() {
+        // The argument's own literal quotes are content, not auto-wrapping,
+        // so they are escaped once by the per-argument encoder and then
+        // again by the outer payload encoding that lets them survive being
+        // embedded in MO2's own invocation line.
         let cmd = MO2Command::new(
             r"C:\Modding\MO2\ModOrganizer.exe",
             r"d:\programs\xedit\xedit64.exe"
@@ -180,7 +484,45 @@ mod tests {
 
         assert_eq!(
             cmd,
-            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\xedit64.exe" -a "-sse -autoexit -autoload \"MyPlugin.esp\"""#
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\xedit64.exe" -a "-sse -autoexit -autoload \"\\\"MyPlugin.esp\\\"\"""#
+        );
+    }
+
+    #[test]
+    fn test_command_with_space_in_argument_is_wrapped_in_quotes() {
+        // An argument containing a space must be wrapped in quotes so it
+        // stays a single token when the payload is re-tokenized, instead of
+        // silently splitting into two arguments downstream.
+        let cmd = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg("Program Files")
+        .arg("-autoexit")
+        .build();
+
+        assert_eq!(
+            cmd,
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\xedit64.exe" -a "\"Program Files\" -autoexit""#
+        );
+    }
+
+    #[test]
+    fn test_command_with_empty_argument_is_preserved() {
+        // An empty argument must be wrapped as `""` rather than vanishing
+        // from the payload entirely.
+        let cmd = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg("-flag")
+        .arg("")
+        .arg("-other")
+        .build();
+
+        assert_eq!(
+            cmd,
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\xedit64.exe" -a "-flag \"\" -other""#
         );
     }
 
@@ -214,7 +556,7 @@ mod tests {
 
         assert_eq!(
             cmd,
-            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\SSEEdit64.exe" -a "-qac -autoexit -autoload \"MyPlugin.esp\"""#
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\SSEEdit64.exe" -a "\"-qac -autoexit -autoload \\\"MyPlugin.esp\\\"\"""#
         );
     }
 
@@ -230,7 +572,7 @@ mod tests {
 
         assert_eq!(
             cmd,
-            r#""C:\MO2\ModOrganizer.exe" run "C:\tools\program.exe" -a "-flag \"value with spaces\" \"another quoted value\"""#
+            r#""C:\MO2\ModOrganizer.exe" run "C:\tools\program.exe" -a "\"-flag \\\"value with spaces\\\"\" \"\\\"another quoted value\\\"\"""#
         );
     }
 
@@ -251,13 +593,96 @@ mod tests {
         // Verify the program is correct
         assert_eq!(cmd.get_program(), OsStr::new(r"C:\Modding\MO2\ModOrganizer.exe"));
 
-        // Verify the arguments
+        // Verify the arguments. This is the per-argument encoded payload
+        // (same as `encode_arguments` feeds into `build`), handed to
+        // `Command::arg` as a single argv entry: `std::process::Command`'s
+        // own `CommandLineToArgvW`-compatible encoding delivers this entry
+        // to the child process transparently, it does not re-split the
+        // individual arguments MO2 recovers from within it.
         let args: Vec<&OsStr> = cmd.get_args().collect();
         assert_eq!(args.len(), 4);
         assert_eq!(args[0], OsStr::new("run"));
         assert_eq!(args[1], OsStr::new(r"d:\programs\xedit\xedit64.exe"));
         assert_eq!(args[2], OsStr::new("-a"));
-        assert_eq!(args[3], OsStr::new(r#"-sse -autoexit "MyPlugin.esp""#));
+        assert_eq!(args[3], OsStr::new(r#"-sse -autoexit "\"MyPlugin.esp\"""#));
+    }
+
+    #[test]
+    fn test_trailing_backslash_before_quote_is_doubled() {
+        // A backslash directly followed by a quote must be doubled (and the
+        // quote escaped), otherwise CommandLineToArgvW would fold it into
+        // the quote escape instead of treating it as a literal backslash.
+        let cmd = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg(r#""C:\Output\""#)
+        .build();
+
+        assert_eq!(
+            cmd,
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\xedit64.exe" -a "\"\\\"C:\Output\\\\\\\"\"""#
+        );
+    }
+
+    #[test]
+    fn test_non_final_argument_trailing_backslash_not_doubled() {
+        // An argument with no space, tab, or quote is emitted verbatim with
+        // no escaping at all, so a trailing backslash stays a single
+        // backslash rather than being doubled.
+        let cmd = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg(r#"-o:C:\Output\"#)
+        .arg("-autoexit")
+        .build();
+
+        assert_eq!(
+            cmd,
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\xedit64.exe" -a "-o:C:\Output\ -autoexit""#
+        );
+    }
+
+    #[test]
+    fn test_raw_arg_bypasses_escaping() {
+        let raw = r#"echo "hello" && echo "world""#;
+
+        let cmd = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"C:\Windows\System32\cmd.exe"
+        )
+        .arg("/c")
+        .raw_arg(raw)
+        .build();
+
+        // The raw fragment is spliced into the per-argument payload with its
+        // embedded quotes untouched, rather than being backslash-escaped
+        // like a `Regular` argument. The whole payload is still escaped once
+        // as it is embedded in MO2's own invocation line, so those untouched
+        // quotes pick up a protecting backslash at that outer layer.
+        assert_eq!(
+            cmd,
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "C:\Windows\System32\cmd.exe" -a "/c echo \"hello\" && echo \"world\"""#
+        );
+    }
+
+    #[test]
+    fn test_raw_arg_mixed_with_regular_args() {
+        let raw = r#"-autoload "MyPlugin.esp""#;
+
+        let cmd = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg("-sse")
+        .raw_arg(raw)
+        .build();
+
+        assert_eq!(
+            cmd,
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\xedit64.exe" -a "-sse -autoload \"MyPlugin.esp\"""#
+        );
     }
 
     #[test]
@@ -276,4 +701,222 @@ mod tests {
         assert_eq!(args[0], OsStr::new("run"));
         assert_eq!(args[1], OsStr::new(r"C:\tools\notepad++.exe"));
     }
+
+    #[test]
+    fn test_parse_without_args() {
+        let cmd = MO2Command::parse(
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\xedit64.exe""#
+        ).unwrap();
+
+        assert_eq!(cmd.mo2_path, PathBuf::from(r"C:\Modding\MO2\ModOrganizer.exe"));
+        assert_eq!(cmd.program_path, PathBuf::from(r"d:\programs\xedit\xedit64.exe"));
+        assert!(cmd.arguments.is_empty());
+    }
+
+    #[test]
+    fn test_parse_round_trip_with_simple_args() {
+        let original = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg("-sse")
+        .arg("-autoexit")
+        .arg("-autoload")
+        .build();
+
+        let parsed = MO2Command::parse(&original).unwrap();
+
+        assert_eq!(parsed.build(), original);
+    }
+
+    #[test]
+    fn test_parse_unwraps_auto_wrapped_argument_to_its_original_value() {
+        // The quotes `escape_arg_for_mo2` adds around an argument containing
+        // a space are grouping syntax, not literal characters: `parse`
+        // strips them when recovering the argument, the same way
+        // CommandLineToArgvW strips them for the downstream program.
+        let original = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg("-sse")
+        .arg("-autoload")
+        .arg("Program Files")
+        .build();
+
+        let parsed = MO2Command::parse(&original).unwrap();
+
+        assert_eq!(parsed.arguments[2], Argument::Regular("Program Files".to_string()));
+        assert_eq!(parsed.build(), original);
+    }
+
+    #[test]
+    fn test_parse_preserves_a_literal_quote_character_in_an_argument() {
+        // Unlike the quotes `escape_arg_for_mo2` adds around a space, a
+        // quote character that is itself part of the argument's value is
+        // literal data, not grouping syntax, so it survives the round trip
+        // unchanged rather than being stripped.
+        let original = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg("-sse")
+        .arg("-autoload")
+        .arg(r#""MyPlugin.esp""#)
+        .build();
+
+        let parsed = MO2Command::parse(&original).unwrap();
+
+        assert_eq!(
+            parsed.arguments[2],
+            Argument::Regular(r#""MyPlugin.esp""#.to_string())
+        );
+        assert_eq!(parsed.build(), original);
+    }
+
+    #[test]
+    fn test_parse_round_trip_with_space_containing_argument() {
+        let original = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg("-sse")
+        .arg("-autoload")
+        .arg("Program Files")
+        .build();
+
+        let parsed = MO2Command::parse(&original).unwrap();
+
+        assert_eq!(parsed.build(), original);
+    }
+
+    #[test]
+    fn test_parse_round_trip_with_empty_argument() {
+        let original = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg("-flag")
+        .arg("")
+        .arg("-other")
+        .build();
+
+        let parsed = MO2Command::parse(&original).unwrap();
+
+        assert_eq!(parsed.arguments[1], Argument::Regular(String::new()));
+        assert_eq!(parsed.build(), original);
+    }
+
+    #[test]
+    fn test_parse_then_modify_an_argument() {
+        let original = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg("-sse")
+        .arg("-autoload")
+        .arg(r#""OldPlugin.esp""#)
+        .build();
+
+        let mut parsed = MO2Command::parse(&original).unwrap();
+        parsed.arguments[2] = Argument::Regular(r#""NewPlugin.esp""#.to_string());
+
+        assert_eq!(
+            parsed.build(),
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\xedit64.exe" -a "-sse -autoload \"\\\"NewPlugin.esp\\\"\"""#
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_run_keyword() {
+        let err = MO2Command::parse(
+            r#""C:\Modding\MO2\ModOrganizer.exe" launch "d:\programs\xedit\xedit64.exe""#
+        ).unwrap_err();
+
+        assert_eq!(err, ParseError::MissingRunKeyword);
+    }
+
+    #[test]
+    fn test_parse_rejects_empty_string() {
+        let err = MO2Command::parse("").unwrap_err();
+        assert_eq!(err, ParseError::MissingMo2Path);
+    }
+
+    #[test]
+    fn test_default_quoting_is_msvcrt() {
+        let cmd = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .arg(r#""MyPlugin.esp""#)
+        .build();
+
+        assert_eq!(
+            cmd,
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\xedit64.exe" -a "\"\\\"MyPlugin.esp\\\"\"""#
+        );
+    }
+
+    #[test]
+    fn test_cmd_exe_quoting_caret_escapes_metacharacters() {
+        let cmd = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"C:\Windows\System32\cmd.exe"
+        )
+        .quoting(QuotingStyle::CmdExe)
+        .arg("/c")
+        .arg("dir & echo done")
+        .build();
+
+        assert_eq!(
+            cmd,
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "C:\Windows\System32\cmd.exe" -a "/c \"dir ^& echo done\"""#
+        );
+    }
+
+    #[test]
+    fn test_cmd_exe_quoting_space_containing_argument_survives_mo2_tokenizing() {
+        // `MO2Command`'s own `-a` tokenizer parses the whole payload with the
+        // `CommandLineToArgvW` algorithm regardless of `QuotingStyle`, so the
+        // caret-escaped argument must still be quote-wrapped to come back out
+        // as one argument rather than being split on its unescaped space.
+        let cmd = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"C:\Windows\System32\cmd.exe"
+        )
+        .quoting(QuotingStyle::CmdExe)
+        .arg("/c")
+        .arg("dir & echo done")
+        .build();
+
+        let parsed = MO2Command::parse(&cmd).unwrap();
+
+        assert_eq!(parsed.arguments.len(), 2);
+        assert_eq!(parsed.arguments[0], Argument::Regular("/c".to_string()));
+        assert_eq!(
+            parsed.arguments[1],
+            Argument::Regular("dir ^& echo done".to_string())
+        );
+    }
+
+    #[test]
+    fn test_none_quoting_emits_arguments_verbatim() {
+        let plugin_arg = r#""MyPlugin.esp""#;
+
+        let cmd = MO2Command::new(
+            r"C:\Modding\MO2\ModOrganizer.exe",
+            r"d:\programs\xedit\xedit64.exe"
+        )
+        .quoting(QuotingStyle::None)
+        .arg(plugin_arg)
+        .build();
+
+        // `QuotingStyle::None` skips the per-argument encoder, but the
+        // payload is still escaped once as a whole so it survives being
+        // embedded in MO2's own invocation line.
+        assert_eq!(
+            cmd,
+            r#""C:\Modding\MO2\ModOrganizer.exe" run "d:\programs\xedit\xedit64.exe" -a "\"MyPlugin.esp\"""#
+        );
+    }
 }